@@ -0,0 +1,70 @@
+use buildor::migrator::{self, TableNames};
+use buildor::models::{common::ExecutionError, request::RequestError, response::Response};
+use buildor::telemetry;
+use buildor::utils::{load_env_var, Clients};
+use error_stack::{Report, ResultExt};
+use lambda_runtime::{service_fn, LambdaEvent};
+use serde_json::{json, Value};
+use tracing::{debug, error, info, Instrument};
+
+#[tokio::main]
+async fn main() -> Result<(), Value> {
+    telemetry::init();
+
+    debug!("Creating service fn for handler");
+    let func = service_fn(handler);
+    debug!("Executing handler from runtime");
+    let result = lambda_runtime::run(func).await;
+    debug!("Evaluating handler result");
+    match result {
+        Ok(res) => {
+            info!("Success");
+            Ok(res)
+        }
+        Err(err) => {
+            error!("Handler exception: {}", err);
+            Err(json!(RequestError::internal()))
+        }
+    }
+}
+
+async fn handler(event: LambdaEvent<Value>) -> Result<Value, Report<ExecutionError>> {
+    let (event, context) = event.into_parts();
+    let span = telemetry::request_span(&context);
+    async move {
+        info!("Start handler execution");
+        debug!("event: {:?}", event);
+        debug!("context: {:?}", context);
+
+        debug!("Load env vars");
+        let tables = TableNames {
+            users: load_env_var("TABLE_NAME_USERS", None).change_context(ExecutionError)?,
+            projects: load_env_var("TABLE_NAME_PROJECTS", None).change_context(ExecutionError)?,
+            project_deployments: load_env_var("TABLE_NAME_PROJECT_DEPLOYMENTS", None)
+                .change_context(ExecutionError)?,
+            artifacts: load_env_var("TABLE_NAME_ARTIFACTS", None).change_context(ExecutionError)?,
+            stats: load_env_var("TABLE_NAME_STATS", None).change_context(ExecutionError)?,
+        };
+
+        let client = Clients::dynamodb().await;
+
+        match migrator::run(&client, &tables).await {
+            Ok(applied) => {
+                info!("Applied migrations: {:?}", applied);
+                Ok(Response::new(json!({ "applied": applied }), 200))
+            }
+            Err(error) => {
+                error!(
+                    "Failed to run migrations: {}",
+                    error.change_context(ExecutionError)
+                );
+                Ok(Response::new(
+                    json!({ "error": "Failed to run migrations" }),
+                    500,
+                ))
+            }
+        }
+    }
+    .instrument(span)
+    .await
+}
@@ -0,0 +1,81 @@
+use buildor::{
+    handlers::project_deployments::ProjectDeploymentsHandler,
+    models::{
+        common::ExecutionError, project_deployment::ProjectDeploymentError, request::RequestError,
+        response::Response,
+    },
+    telemetry,
+    utils::{load_env_var, Clients},
+};
+use error_stack::{Report, ResultExt};
+use lambda_runtime::{service_fn, LambdaEvent};
+use serde_json::{json, Value};
+use tracing::{debug, error, info, Instrument};
+
+use buildor::models::handlers::HandlerList;
+
+#[tokio::main]
+async fn main() -> Result<(), Value> {
+    telemetry::init();
+
+    debug!("Creating service fn for handler");
+    let func = service_fn(handler);
+    debug!("Executing handler from runtime");
+    let result = lambda_runtime::run(func).await;
+    debug!("Evaluating handler result");
+    match result {
+        Ok(res) => {
+            info!("Success");
+            Ok(res)
+        }
+        Err(err) => {
+            error!("Handler exception: {}", err);
+            Err(json!(RequestError::internal()))
+        }
+    }
+}
+
+async fn handler(event: LambdaEvent<Value>) -> Result<Value, Report<ExecutionError>> {
+    let (event, context) = event.into_parts();
+    let span = telemetry::request_span(&context);
+    async move {
+        info!("Start handler execution");
+
+        debug!("Load env vars");
+        #[allow(non_snake_case)]
+        let TABLE_NAME = load_env_var("TABLE_NAME", None).change_context(ExecutionError)?;
+        debug!("TABLE_NAME: {}", TABLE_NAME);
+
+        debug!("event: {:?}", event);
+        debug!("context: {:?}", context);
+
+        let cursor = event["queryStringParameters"]["cursor"]
+            .as_str()
+            .map(String::from);
+        let limit = event["queryStringParameters"]["limit"]
+            .as_str()
+            .and_then(|value| value.parse::<i32>().ok());
+
+        let table = Clients::dynamodb().await;
+        let pdh = ProjectDeploymentsHandler::new(table, TABLE_NAME);
+
+        match pdh.list(cursor, limit).await {
+            Ok(page) => Ok(Response::new(
+                json!({ "data": page.items, "next_cursor": page.next_cursor }),
+                200,
+            )),
+            Err(error) => {
+                error!(
+                    "Failed to list project deployments: {}",
+                    error.change_context(ExecutionError)
+                );
+                Ok(Response::new(
+                    json!(ProjectDeploymentError::not_found()),
+                    400,
+                ))
+            }
+        }
+    }
+    .instrument(span)
+    .await
+}
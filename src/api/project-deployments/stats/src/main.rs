@@ -0,0 +1,84 @@
+use buildor::{
+    handlers::stats::StatsHandler,
+    models::{
+        common::ExecutionError, request::RequestError, response::Response, stats::StatsError,
+    },
+    telemetry,
+    utils::{load_env_var, Clients},
+};
+use error_stack::{Report, ResultExt};
+use lambda_runtime::{service_fn, LambdaEvent};
+use serde_json::{json, Value};
+use tracing::{debug, error, info, Instrument};
+
+#[tokio::main]
+async fn main() -> Result<(), Value> {
+    telemetry::init();
+
+    debug!("Creating service fn for handler");
+    let func = service_fn(handler);
+    debug!("Executing handler from runtime");
+    let result = lambda_runtime::run(func).await;
+    debug!("Evaluating handler result");
+    match result {
+        Ok(res) => {
+            info!("Success");
+            Ok(res)
+        }
+        Err(err) => {
+            error!("Handler exception: {}", err);
+            Err(json!(RequestError::internal()))
+        }
+    }
+}
+
+async fn handler(event: LambdaEvent<Value>) -> Result<Value, Report<ExecutionError>> {
+    let (event, context) = event.into_parts();
+    let span = telemetry::request_span(&context);
+    async move {
+        info!("Start handler execution");
+
+        debug!("Load env vars");
+        #[allow(non_snake_case)]
+        let TABLE_NAME = load_env_var("TABLE_NAME", None).change_context(ExecutionError)?;
+        debug!("TABLE_NAME: {}", TABLE_NAME);
+        #[allow(non_snake_case)]
+        let TABLE_NAME_PROJECT_DEPLOYMENTS =
+            load_env_var("TABLE_NAME_PROJECT_DEPLOYMENTS", None).change_context(ExecutionError)?;
+        debug!(
+            "TABLE_NAME_PROJECT_DEPLOYMENTS: {}",
+            TABLE_NAME_PROJECT_DEPLOYMENTS
+        );
+
+        debug!("event: {:?}", event);
+        debug!("context: {:?}", context);
+
+        let sh = StatsHandler::new(
+            Clients::dynamodb().await,
+            TABLE_NAME,
+            TABLE_NAME_PROJECT_DEPLOYMENTS,
+        );
+
+        let stats = match sh.compute().await {
+            Ok(stats) => stats,
+            Err(error) => {
+                error!(
+                    "Failed to compute deployment stats: {}",
+                    error.change_context(ExecutionError)
+                );
+                return Ok(Response::new(json!(StatsError::computation_failed()), 400));
+            }
+        };
+
+        if let Err(error) = sh.cache(&stats).await {
+            error!(
+                "Failed to cache deployment stats: {}",
+                error.change_context(ExecutionError)
+            );
+        }
+
+        Ok(Response::new(json!(stats), 200))
+    }
+    .instrument(span)
+    .await
+}
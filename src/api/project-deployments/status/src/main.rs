@@ -0,0 +1,129 @@
+use error_stack::{Report, ResultExt};
+use lambda_runtime::{service_fn, LambdaEvent};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tracing::{debug, error, info, Instrument};
+
+use buildor::{
+    handlers::{
+        artifacts::ArtifactsHandler, build_status::BuildStatusHandler,
+        project_deployments::ProjectDeploymentsHandler,
+    },
+    models::{
+        common::ExecutionError, project_deployment::ProjectDeploymentError, request::RequestError,
+        response::Response,
+    },
+    telemetry,
+    utils::{load_env_var, parse_request_body_payload, Clients},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Value> {
+    telemetry::init();
+
+    debug!("Creating service fn for handler");
+    let func = service_fn(handler);
+    debug!("Executing handler from runtime");
+    let result = lambda_runtime::run(func).await;
+    debug!("Evaluating handler result");
+    match result {
+        Ok(res) => {
+            info!("Success");
+            Ok(res)
+        }
+        Err(err) => {
+            error!("Handler exception: {}", err);
+            Err(json!(RequestError::internal()))
+        }
+    }
+}
+
+/// Body shape for an API poll of a known deployment.
+#[derive(Debug, Deserialize)]
+struct BuildStatusPollRequest {
+    deployment_uuid: String,
+}
+
+/// Minimal shape of an EventBridge CodeBuild state-change event, as
+/// documented at
+/// https://docs.aws.amazon.com/codebuild/latest/userguide/sample-build-notifications.html
+#[derive(Debug, Deserialize)]
+struct CodeBuildStateChangeEvent {
+    detail: CodeBuildStateChangeDetail,
+}
+#[derive(Debug, Deserialize)]
+struct CodeBuildStateChangeDetail {
+    #[serde(rename = "build-id")]
+    build_id: String,
+}
+
+async fn handler(event: LambdaEvent<Value>) -> Result<Value, Report<ExecutionError>> {
+    let (event, context) = event.into_parts();
+    let span = telemetry::request_span(&context);
+    async move {
+        info!("Start handler execution");
+
+        debug!("Load env vars");
+        #[allow(non_snake_case)]
+        let TABLE_NAME_PROJECT_DEPLOYMENTS =
+            load_env_var("TABLE_NAME_PROJECT_DEPLOYMENTS", None).change_context(ExecutionError)?;
+        #[allow(non_snake_case)]
+        let TABLE_NAME_ARTIFACTS =
+            load_env_var("TABLE_NAME_ARTIFACTS", None).change_context(ExecutionError)?;
+        debug!(
+            "TABLE_NAME_PROJECT_DEPLOYMENTS: {}",
+            TABLE_NAME_PROJECT_DEPLOYMENTS
+        );
+        debug!("TABLE_NAME_ARTIFACTS: {}", TABLE_NAME_ARTIFACTS);
+
+        debug!("event: {:?}", event);
+        debug!("context: {:?}", context);
+
+        let deployments = ProjectDeploymentsHandler::new(
+            Clients::dynamodb().await,
+            TABLE_NAME_PROJECT_DEPLOYMENTS,
+        );
+        let artifacts = ArtifactsHandler::new(
+            Clients::dynamodb().await,
+            TABLE_NAME_ARTIFACTS,
+            Clients::s3().await,
+        );
+        let bsh = BuildStatusHandler::new(Clients::codebuild().await, deployments)
+            .with_artifacts(artifacts);
+
+        // EventBridge invokes the function directly with the state-change event,
+        // there is no API Gateway `body` wrapper in that shape.
+        let result = match serde_json::from_value::<CodeBuildStateChangeEvent>(event.clone()) {
+            Ok(state_change) => {
+                info!("Handling EventBridge build state-change event");
+                bsh.sync_by_build_id(&state_change.detail.build_id).await
+            }
+            Err(_) => {
+                debug!("Parse body payload");
+                let body =
+                    match parse_request_body_payload::<BuildStatusPollRequest>(&event["body"]) {
+                        Ok(value) => value,
+                        Err(err) => return Ok(json!(err)),
+                    };
+
+                bsh.sync(body.deployment_uuid).await
+            }
+        };
+
+        match result {
+            Ok(deployment) => Ok(Response::new(json!(deployment), 200)),
+            Err(error) => {
+                error!(
+                    "Failed to sync build status: {}",
+                    error.change_context(ExecutionError)
+                );
+                Ok(Response::new(
+                    json!(ProjectDeploymentError::update_failed()),
+                    400,
+                ))
+            }
+        }
+    }
+    .instrument(span)
+    .await
+}
@@ -0,0 +1,73 @@
+use buildor::{
+    handlers::projects::ProjectsHandler,
+    models::{
+        common::ExecutionError, project::ProjectError, request::RequestError, response::Response,
+    },
+    telemetry,
+    utils::{load_env_var, Clients},
+};
+use error_stack::{Report, ResultExt};
+use lambda_runtime::{service_fn, LambdaEvent};
+use serde_json::{json, Value};
+use tracing::{debug, error, info, Instrument};
+
+use buildor::models::handlers::HandlerGet;
+
+#[tokio::main]
+async fn main() -> Result<(), Value> {
+    telemetry::init();
+
+    debug!("Creating service fn for handler");
+    let func = service_fn(handler);
+    debug!("Executing handler from runtime");
+    let result = lambda_runtime::run(func).await;
+    debug!("Evaluating handler result");
+    match result {
+        Ok(res) => {
+            info!("Success");
+            Ok(res)
+        }
+        Err(err) => {
+            error!("Handler exception: {}", err);
+            Err(json!(RequestError::internal()))
+        }
+    }
+}
+
+async fn handler(event: LambdaEvent<Value>) -> Result<Value, Report<ExecutionError>> {
+    let (event, context) = event.into_parts();
+    let span = telemetry::request_span(&context);
+    async move {
+        info!("Start handler execution");
+
+        debug!("Load env vars");
+        #[allow(non_snake_case)]
+        let TABLE_NAME = load_env_var("TABLE_NAME", None).change_context(ExecutionError)?;
+        debug!("TABLE_NAME: {}", TABLE_NAME);
+
+        debug!("event: {:?}", event);
+        debug!("context: {:?}", context);
+
+        let uuid = match event["pathParameters"]["uuid"].as_str() {
+            Some(value) => value.to_string(),
+            None => return Ok(Response::new(json!(ProjectError::not_found()), 400)),
+        };
+
+        let table = Clients::dynamodb().await;
+        let ph = ProjectsHandler::new(table, TABLE_NAME);
+
+        match ph.get(uuid).await {
+            Ok(Some(project)) => Ok(Response::new(json!(project), 200)),
+            Ok(None) => Ok(Response::new(json!(ProjectError::not_found()), 404)),
+            Err(error) => {
+                error!(
+                    "Failed to get project: {}",
+                    error.change_context(ExecutionError)
+                );
+                Ok(Response::new(json!(ProjectError::not_found()), 404))
+            }
+        }
+    }
+    .instrument(span)
+    .await
+}
@@ -0,0 +1,77 @@
+use buildor::{
+    handlers::projects::ProjectsHandler,
+    models::{
+        common::ExecutionError,
+        project::{ProjectError, ProjectUpdatePayload},
+        request::RequestError,
+        response::Response,
+    },
+    telemetry,
+    utils::{load_env_var, parse_request_body_payload, Clients},
+};
+use error_stack::{Report, ResultExt};
+use lambda_runtime::{service_fn, LambdaEvent};
+use serde_json::{json, Value};
+use tracing::{debug, error, info, Instrument};
+
+use buildor::models::handlers::HandlerUpdate;
+
+#[tokio::main]
+async fn main() -> Result<(), Value> {
+    telemetry::init();
+
+    debug!("Creating service fn for handler");
+    let func = service_fn(handler);
+    debug!("Executing handler from runtime");
+    let result = lambda_runtime::run(func).await;
+    debug!("Evaluating handler result");
+    match result {
+        Ok(res) => {
+            info!("Success");
+            Ok(res)
+        }
+        Err(err) => {
+            error!("Handler exception: {}", err);
+            Err(json!(RequestError::internal()))
+        }
+    }
+}
+
+async fn handler(event: LambdaEvent<Value>) -> Result<Value, Report<ExecutionError>> {
+    let (event, context) = event.into_parts();
+    let span = telemetry::request_span(&context);
+    async move {
+        info!("Start handler execution");
+
+        debug!("Load env vars");
+        #[allow(non_snake_case)]
+        let TABLE_NAME = load_env_var("TABLE_NAME", None).change_context(ExecutionError)?;
+        debug!("TABLE_NAME: {}", TABLE_NAME);
+
+        debug!("event: {:?}", event);
+        debug!("context: {:?}", context);
+
+        debug!("Parse body payload");
+        let body = match parse_request_body_payload::<ProjectUpdatePayload>(&event["body"]) {
+            Ok(value) => value,
+            Err(err) => return Ok(json!(err)),
+        };
+        debug!("Body: {:?}", body);
+
+        let table = Clients::dynamodb().await;
+        let ph = ProjectsHandler::new(table, TABLE_NAME);
+
+        match ph.update(body).await {
+            Ok(project) => Ok(Response::new(json!(project), 200)),
+            Err(error) => {
+                error!(
+                    "Failed to update project: {}",
+                    error.change_context(ExecutionError)
+                );
+                Ok(Response::new(json!(ProjectError::update_failed()), 400))
+            }
+        }
+    }
+    .instrument(span)
+    .await
+}
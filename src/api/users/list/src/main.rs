@@ -1,8 +1,12 @@
 use buildor::handlers::users::UsersHandler;
+use buildor::models::handlers::HandlerList;
 use buildor::models::response::Response;
+use buildor::models::user::UserError;
+use buildor::telemetry;
 use buildor::utils::get_table_client;
 use lambda_runtime::{service_fn, LambdaEvent};
 use serde_json::{json, Value};
+use tracing::{debug, error, info, Instrument};
 
 use error_stack::{Context, Report, ResultExt};
 use std::fmt;
@@ -43,44 +47,63 @@ fn load_env_var(name: &str) -> Result<String, Report<RequiredEnvVarError>> {
 
 #[tokio::main]
 async fn main() -> Result<(), Value> {
-    env_logger::init();
+    telemetry::init();
 
-    println!("Creating service fn for handler");
+    debug!("Creating service fn for handler");
     let func = service_fn(handler);
-    println!("Executing handler from runtime");
+    debug!("Executing handler from runtime");
     let result = lambda_runtime::run(func).await;
-    println!("Evaluating handler result");
+    debug!("Evaluating handler result");
     match result {
         Ok(res) => {
-            println!("Success");
+            info!("Success");
             Ok(res)
         }
         Err(err) => {
-            println!("Handler exception: {}", err);
+            error!("Handler exception: {}", err);
             Err(json!({ "error": format!("Internal server error") }))
         }
     }
 }
 
 async fn handler(event: LambdaEvent<Value>) -> Result<Value, Report<ExecutionError>> {
-    println!("Start handler execution");
+    let (event, context) = event.into_parts();
+    let span = telemetry::request_span(&context);
+    async move {
+        info!("Start handler execution");
 
-    println!("Load env vars");
-    #[allow(non_snake_case)]
-    let TABLE_NAME = load_env_var("TABLE_NAME").unwrap();
-    #[allow(non_snake_case)]
-    let TABLE_REGION = load_env_var("TABLE_REGION").unwrap();
-    println!("TABLE_NAME: {}", TABLE_NAME);
-    println!("TABLE_REGION: {}", TABLE_REGION);
+        debug!("Load env vars");
+        #[allow(non_snake_case)]
+        let TABLE_NAME = load_env_var("TABLE_NAME").unwrap();
+        #[allow(non_snake_case)]
+        let TABLE_REGION = load_env_var("TABLE_REGION").unwrap();
+        debug!("TABLE_NAME: {}", TABLE_NAME);
+        debug!("TABLE_REGION: {}", TABLE_REGION);
 
-    println!("Parse event and context objects");
-    let (event, context) = event.into_parts();
-    println!("event: {:?}", event);
-    println!("context: {:?}", context);
+        debug!("event: {:?}", event);
+        debug!("context: {:?}", context);
+
+        let cursor = event["queryStringParameters"]["cursor"]
+            .as_str()
+            .map(String::from);
+        let limit = event["queryStringParameters"]["limit"]
+            .as_str()
+            .and_then(|value| value.parse::<i32>().ok());
 
-    let table = get_table_client().await;
-    let uh = UsersHandler::new(table, TABLE_NAME);
-    let users = uh.list().await;
+        let table = get_table_client().await;
+        let uh = UsersHandler::new(table, TABLE_NAME);
 
-    Ok(Response::new(json!({ "data": users }), 200))
+        match uh.list(cursor, limit).await {
+            Ok(page) => Ok(Response::new(
+                json!({ "data": page.items, "next_cursor": page.next_cursor }),
+                200,
+            )),
+            Err(error) => {
+                error!("Failed to list users: {}", error);
+                Ok(Response::new(json!(UserError::not_found()), 400))
+            }
+        }
+    }
+    .instrument(span)
+    .await
 }
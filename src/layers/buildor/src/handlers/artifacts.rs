@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::model::AttributeValue;
+use aws_sdk_dynamodb::Client as DynamoDBClient;
+use aws_sdk_s3::presigning::config::PresigningConfig;
+use aws_sdk_s3::Client as S3Client;
+use error_stack::{Report, ResultExt};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::models::artifact::{Artifact, ArtifactCreatePayload, ArtifactError};
+use crate::models::common::AsDynamoDBAttributeValue;
+use crate::models::handlers::{HandlerCreate, HandlerGet};
+
+/// How long a presigned download URL stays valid for.
+const DOWNLOAD_URL_TTL: Duration = Duration::from_secs(15 * 60);
+
+pub struct ArtifactsHandler {
+    client: DynamoDBClient,
+    table: String,
+    s3: S3Client,
+}
+impl ArtifactsHandler {
+    pub fn new(client: DynamoDBClient, table: String, s3: S3Client) -> Self {
+        Self { client, table, s3 }
+    }
+
+    fn from_item(item: &HashMap<String, AttributeValue>) -> Option<Artifact> {
+        Some(Artifact {
+            uuid: item.get("uuid")?.as_s().ok()?.to_string(),
+            deployment_uuid: item.get("deployment_uuid")?.as_s().ok()?.to_string(),
+            bucket: item.get("bucket")?.as_s().ok()?.to_string(),
+            key: item.get("key")?.as_s().ok()?.to_string(),
+            size: item
+                .get("size")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse().ok()),
+            content_type: item
+                .get("content_type")
+                .and_then(|v| v.as_s().ok())
+                .map(String::from),
+        })
+    }
+
+    /// Scans for an artifact already recorded for the given deployment.
+    ///
+    /// Used to make [`HandlerCreate::create`] idempotent per deployment,
+    /// since build-status syncs can observe a succeeded build more than
+    /// once (at-least-once EventBridge delivery, repeated API polls).
+    pub async fn find_by_deployment(
+        &self,
+        deployment_uuid: &str,
+    ) -> Result<Option<Artifact>, Report<ArtifactError>> {
+        let result = self
+            .client
+            .scan()
+            .table_name(&self.table)
+            .filter_expression("deployment_uuid = :deployment_uuid")
+            .expression_attribute_values(
+                ":deployment_uuid",
+                AttributeValue::S(deployment_uuid.to_string()),
+            )
+            .send()
+            .await
+            .change_context(ArtifactError)?;
+
+        Ok(result
+            .items()
+            .unwrap_or_default()
+            .iter()
+            .find_map(Self::from_item))
+    }
+
+    /// Generates a time-limited presigned GET URL for the artifact's object.
+    pub async fn presigned_download_url(
+        &self,
+        artifact: &Artifact,
+    ) -> Result<String, Report<ArtifactError>> {
+        let presigning_config =
+            PresigningConfig::expires_in(DOWNLOAD_URL_TTL).change_context(ArtifactError)?;
+
+        let presigned = self
+            .s3
+            .get_object()
+            .bucket(&artifact.bucket)
+            .key(&artifact.key)
+            .presigned(presigning_config)
+            .await
+            .change_context(ArtifactError)?;
+
+        Ok(presigned.uri().to_string())
+    }
+}
+
+#[async_trait]
+impl HandlerCreate for ArtifactsHandler {
+    type Error = ArtifactError;
+    type Payload = ArtifactCreatePayload;
+    type Output = Artifact;
+
+    async fn create(
+        &self,
+        payload: ArtifactCreatePayload,
+    ) -> Result<Artifact, Report<ArtifactError>> {
+        let artifact = Artifact::new(payload);
+
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(artifact.as_hashmap()))
+            .send()
+            .await
+            .change_context(ArtifactError)?;
+
+        Ok(artifact)
+    }
+}
+
+#[async_trait]
+impl HandlerGet for ArtifactsHandler {
+    type Error = ArtifactError;
+    type Output = Artifact;
+
+    async fn get(&self, uuid: String) -> Result<Option<Artifact>, Report<ArtifactError>> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key("uuid", AttributeValue::S(uuid))
+            .send()
+            .await
+            .change_context(ArtifactError)?;
+
+        Ok(result.item().and_then(Self::from_item))
+    }
+}
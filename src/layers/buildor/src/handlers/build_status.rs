@@ -0,0 +1,156 @@
+use aws_sdk_codebuild::Client;
+use error_stack::{Report, ResultExt};
+
+use crate::handlers::artifacts::ArtifactsHandler;
+use crate::handlers::project_deployments::ProjectDeploymentsHandler;
+use crate::models::artifact::ArtifactCreatePayload;
+use crate::models::handlers::{HandlerCreate, HandlerGet, HandlerUpdate};
+use crate::models::project_deployment::{
+    BuildPhase, BuildStatus, ProjectDeployment, ProjectDeploymentError,
+    ProjectDeploymentUpdatePayload,
+};
+use crate::utils::parse_s3_artifact_location;
+
+/// Syncs a stored [`ProjectDeployment`] with the CodeBuild build it tracks.
+///
+/// Driven either by an API poll (given a deployment uuid) or by an
+/// EventBridge CodeBuild state-change event carrying the build id directly.
+pub struct BuildStatusHandler {
+    codebuild: Client,
+    deployments: ProjectDeploymentsHandler,
+    artifacts: Option<ArtifactsHandler>,
+}
+impl BuildStatusHandler {
+    pub fn new(codebuild: Client, deployments: ProjectDeploymentsHandler) -> Self {
+        Self {
+            codebuild,
+            deployments,
+            artifacts: None,
+        }
+    }
+
+    /// Enables recording an artifact once the tracked build succeeds.
+    pub fn with_artifacts(mut self, artifacts: ArtifactsHandler) -> Self {
+        self.artifacts = Some(artifacts);
+        self
+    }
+
+    /// Fetches the deployment, looks up its build in CodeBuild via
+    /// `batch_get_builds`, and persists the latest status and phase
+    /// timestamps.
+    pub async fn sync(
+        &self,
+        deployment_uuid: String,
+    ) -> Result<ProjectDeployment, Report<ProjectDeploymentError>> {
+        let deployment = self
+            .deployments
+            .get(deployment_uuid.clone())
+            .await?
+            .ok_or_else(|| Report::new(ProjectDeploymentError))?;
+
+        let result = self
+            .codebuild
+            .batch_get_builds()
+            .ids(deployment.build.id.clone())
+            .send()
+            .await
+            .change_context(ProjectDeploymentError)?;
+
+        let build = result
+            .builds()
+            .and_then(|builds| builds.first())
+            .ok_or_else(|| Report::new(ProjectDeploymentError))?;
+
+        let status = build
+            .build_status()
+            .map(|status| BuildStatus::from(status.as_str()))
+            .unwrap_or(BuildStatus::Unknown);
+
+        let phases = build
+            .phases()
+            .unwrap_or_default()
+            .iter()
+            .map(|phase| BuildPhase {
+                phase_type: phase
+                    .phase_type()
+                    .map(|t| t.as_str().to_string())
+                    .unwrap_or_default(),
+                phase_status: phase.phase_status().map(|s| s.as_str().to_string()),
+                start_time: phase.start_time().map(|t| t.to_string()),
+                end_time: phase.end_time().map(|t| t.to_string()),
+                duration_in_seconds: phase.duration_in_seconds(),
+            })
+            .collect();
+
+        if status == BuildStatus::Succeeded {
+            if let (Some(artifacts), Some(location)) = (
+                &self.artifacts,
+                build.artifacts().and_then(|a| a.location()),
+            ) {
+                self.record_artifact(artifacts, &deployment_uuid, location)
+                    .await;
+            }
+        }
+
+        self.deployments
+            .update(ProjectDeploymentUpdatePayload {
+                uuid: deployment_uuid,
+                status,
+                phases,
+            })
+            .await
+    }
+
+    /// Records the build's output artifact once the build has succeeded.
+    ///
+    /// Best-effort: a failure here shouldn't stop the deployment's status
+    /// from being synced.
+    async fn record_artifact(
+        &self,
+        artifacts: &ArtifactsHandler,
+        deployment_uuid: &str,
+        location: &str,
+    ) {
+        match artifacts.find_by_deployment(deployment_uuid).await {
+            Ok(Some(_)) => return,
+            Ok(None) => {}
+            Err(error) => {
+                println!("Failed to check for an existing artifact: {}", error);
+                return;
+            }
+        }
+
+        let Some((bucket, key)) = parse_s3_artifact_location(location) else {
+            println!("Failed to parse artifact location: {}", location);
+            return;
+        };
+
+        let payload = ArtifactCreatePayload {
+            deployment_uuid: deployment_uuid.to_string(),
+            bucket,
+            key,
+            size: None,
+            content_type: None,
+        };
+
+        if let Err(error) = artifacts.create(payload).await {
+            println!("Failed to record artifact: {}", error);
+        }
+    }
+
+    /// Same as [`Self::sync`] but resolves the deployment from a CodeBuild
+    /// build id, for callers (EventBridge) that don't know the deployment
+    /// uuid.
+    pub async fn sync_by_build_id(
+        &self,
+        build_id: &str,
+    ) -> Result<ProjectDeployment, Report<ProjectDeploymentError>> {
+        let deployment = self
+            .deployments
+            .find_by_build_id(build_id)
+            .await?
+            .ok_or_else(|| Report::new(ProjectDeploymentError))?;
+
+        self.sync(deployment.uuid).await
+    }
+}
@@ -0,0 +1,6 @@
+pub mod artifacts;
+pub mod build_status;
+pub mod project_deployments;
+pub mod projects;
+pub mod stats;
+pub mod users;
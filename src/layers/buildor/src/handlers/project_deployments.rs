@@ -0,0 +1,273 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::model::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use error_stack::{Report, ResultExt};
+use std::collections::HashMap;
+
+use crate::models::common::AsDynamoDBAttributeValue;
+use crate::models::handlers::{
+    decode_cursor, encode_cursor, HandlerCreate, HandlerDelete, HandlerGet, HandlerList,
+    HandlerUpdate, ListPage,
+};
+use crate::models::project::Project;
+use crate::models::project_deployment::{
+    BuildInfo, BuildPhase, BuildStatus, ProjectDeployment, ProjectDeploymentCreatePayload,
+    ProjectDeploymentError, ProjectDeploymentUpdatePayload,
+};
+
+pub struct ProjectDeploymentsHandler {
+    client: Client,
+    table: String,
+}
+impl ProjectDeploymentsHandler {
+    pub fn new(client: Client, table: String) -> Self {
+        Self { client, table }
+    }
+
+    /// Scans for the deployment tracking the given CodeBuild build id.
+    ///
+    /// Used by the EventBridge CodeBuild state-change entry point, which only
+    /// carries the build id, not the deployment uuid. Matches either the stored
+    /// build id or build ARN, since the event's `build-id` holds the ARN.
+    pub async fn find_by_build_id(
+        &self,
+        build_id: &str,
+    ) -> Result<Option<ProjectDeployment>, Report<ProjectDeploymentError>> {
+        let result = self
+            .client
+            .scan()
+            .table_name(&self.table)
+            .filter_expression("build.#id = :build_id OR build.arn = :build_id")
+            .expression_attribute_names("#id", "id")
+            .expression_attribute_values(":build_id", AttributeValue::S(build_id.to_string()))
+            .send()
+            .await
+            .change_context(ProjectDeploymentError)?;
+
+        Ok(result
+            .items()
+            .unwrap_or_default()
+            .iter()
+            .find_map(Self::from_item))
+    }
+
+    pub(crate) fn from_item(item: &HashMap<String, AttributeValue>) -> Option<ProjectDeployment> {
+        let project = item.get("project")?.as_m().ok()?;
+        let build = item.get("build")?.as_m().ok()?;
+
+        Some(ProjectDeployment {
+            uuid: item.get("uuid")?.as_s().ok()?.to_string(),
+            project: Project {
+                uuid: project.get("uuid")?.as_s().ok()?.to_string(),
+                name: project.get("name")?.as_s().ok()?.to_string(),
+                codebuild_project_name: project
+                    .get("codebuild_project_name")?
+                    .as_s()
+                    .ok()?
+                    .to_string(),
+                repo_url: project.get("repo_url")?.as_s().ok()?.to_string(),
+                install_commands: project
+                    .get("install_commands")
+                    .and_then(|v| v.as_l().ok())
+                    .map(Self::commands_from_list)
+                    .unwrap_or_default(),
+                build_commands: project
+                    .get("build_commands")
+                    .and_then(|v| v.as_l().ok())
+                    .map(Self::commands_from_list)
+                    .unwrap_or_default(),
+                output_folder: project.get("output_folder")?.as_s().ok()?.to_string(),
+                artifact_glob: project.get("artifact_glob")?.as_s().ok()?.to_string(),
+                env_vars: project
+                    .get("env_vars")
+                    .and_then(|v| v.as_m().ok())
+                    .map(|vars| {
+                        vars.iter()
+                            .filter_map(|(name, value)| {
+                                Some((name.clone(), value.as_s().ok()?.to_string()))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            },
+            build: BuildInfo {
+                id: build.get("id")?.as_s().ok()?.to_string(),
+                arn: build.get("arn").and_then(|v| v.as_s().ok()).map(String::from),
+                build_number: build
+                    .get("build_number")
+                    .and_then(|v| v.as_n().ok())
+                    .and_then(|n| n.parse().ok()),
+                start_time: build
+                    .get("start_time")
+                    .and_then(|v| v.as_s().ok())
+                    .map(String::from),
+            },
+            status: item
+                .get("status")
+                .and_then(|v| v.as_s().ok())
+                .map(BuildStatus::from)
+                .unwrap_or(BuildStatus::Unknown),
+            phases: item
+                .get("phases")
+                .and_then(|v| v.as_l().ok())
+                .map(|phases| phases.iter().filter_map(Self::phase_from_attr).collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    fn commands_from_list(commands: &[AttributeValue]) -> Vec<String> {
+        commands
+            .iter()
+            .filter_map(|command| command.as_s().ok().map(String::from))
+            .collect()
+    }
+
+    fn phase_from_attr(attr: &AttributeValue) -> Option<BuildPhase> {
+        let phase = attr.as_m().ok()?;
+
+        Some(BuildPhase {
+            phase_type: phase.get("phase_type")?.as_s().ok()?.to_string(),
+            phase_status: phase
+                .get("phase_status")
+                .and_then(|v| v.as_s().ok())
+                .map(String::from),
+            start_time: phase
+                .get("start_time")
+                .and_then(|v| v.as_s().ok())
+                .map(String::from),
+            end_time: phase
+                .get("end_time")
+                .and_then(|v| v.as_s().ok())
+                .map(String::from),
+            duration_in_seconds: phase
+                .get("duration_in_seconds")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse().ok()),
+        })
+    }
+}
+
+#[async_trait]
+impl HandlerCreate for ProjectDeploymentsHandler {
+    type Error = ProjectDeploymentError;
+    type Payload = ProjectDeploymentCreatePayload;
+    type Output = ProjectDeployment;
+
+    async fn create(
+        &self,
+        payload: ProjectDeploymentCreatePayload,
+    ) -> Result<ProjectDeployment, Report<ProjectDeploymentError>> {
+        let deployment = ProjectDeployment::new(payload);
+
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(deployment.as_hashmap()))
+            .send()
+            .await
+            .change_context(ProjectDeploymentError)?;
+
+        Ok(deployment)
+    }
+}
+
+#[async_trait]
+impl HandlerGet for ProjectDeploymentsHandler {
+    type Error = ProjectDeploymentError;
+    type Output = ProjectDeployment;
+
+    async fn get(&self, uuid: String) -> Result<Option<ProjectDeployment>, Report<ProjectDeploymentError>> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key("uuid", AttributeValue::S(uuid))
+            .send()
+            .await
+            .change_context(ProjectDeploymentError)?;
+
+        Ok(result.item().and_then(Self::from_item))
+    }
+}
+
+#[async_trait]
+impl HandlerUpdate for ProjectDeploymentsHandler {
+    type Error = ProjectDeploymentError;
+    type Payload = ProjectDeploymentUpdatePayload;
+    type Output = ProjectDeployment;
+
+    async fn update(
+        &self,
+        payload: ProjectDeploymentUpdatePayload,
+    ) -> Result<ProjectDeployment, Report<ProjectDeploymentError>> {
+        let deployment = self
+            .get(payload.uuid.clone())
+            .await?
+            .ok_or_else(|| Report::new(ProjectDeploymentError))?;
+
+        let updated = ProjectDeployment {
+            status: payload.status,
+            phases: payload.phases,
+            ..deployment
+        };
+
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(updated.as_hashmap()))
+            .send()
+            .await
+            .change_context(ProjectDeploymentError)?;
+
+        Ok(updated)
+    }
+}
+
+#[async_trait]
+impl HandlerDelete for ProjectDeploymentsHandler {
+    type Error = ProjectDeploymentError;
+
+    async fn delete(&self, uuid: String) -> Result<(), Report<ProjectDeploymentError>> {
+        self.client
+            .delete_item()
+            .table_name(&self.table)
+            .key("uuid", AttributeValue::S(uuid))
+            .send()
+            .await
+            .change_context(ProjectDeploymentError)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HandlerList for ProjectDeploymentsHandler {
+    type Error = ProjectDeploymentError;
+    type Output = ProjectDeployment;
+
+    async fn list(
+        &self,
+        cursor: Option<String>,
+        limit: Option<i32>,
+    ) -> Result<ListPage<ProjectDeployment>, Report<ProjectDeploymentError>> {
+        let result = self
+            .client
+            .scan()
+            .table_name(&self.table)
+            .set_exclusive_start_key(cursor.map(decode_cursor))
+            .set_limit(limit)
+            .send()
+            .await
+            .change_context(ProjectDeploymentError)?;
+
+        Ok(ListPage {
+            items: result
+                .items()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(Self::from_item)
+                .collect(),
+            next_cursor: encode_cursor(result.last_evaluated_key()),
+        })
+    }
+}
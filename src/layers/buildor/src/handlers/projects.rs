@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::model::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use error_stack::{Report, ResultExt};
+use std::collections::HashMap;
+use tracing::instrument;
+
+use crate::models::common::AsDynamoDBAttributeValue;
+use crate::models::handlers::{
+    decode_cursor, encode_cursor, HandlerCreate, HandlerDelete, HandlerGet, HandlerList,
+    HandlerUpdate, ListPage,
+};
+use crate::models::project::{Project, ProjectCreatePayload, ProjectError, ProjectUpdatePayload};
+
+pub struct ProjectsHandler {
+    client: Client,
+    table: String,
+}
+impl ProjectsHandler {
+    pub fn new(client: Client, table: String) -> Self {
+        Self { client, table }
+    }
+
+    fn from_item(item: &HashMap<String, AttributeValue>) -> Option<Project> {
+        Some(Project {
+            uuid: item.get("uuid")?.as_s().ok()?.to_string(),
+            name: item.get("name")?.as_s().ok()?.to_string(),
+            codebuild_project_name: item.get("codebuild_project_name")?.as_s().ok()?.to_string(),
+            repo_url: item.get("repo_url")?.as_s().ok()?.to_string(),
+            install_commands: item
+                .get("install_commands")
+                .and_then(|v| v.as_l().ok())
+                .map(Self::commands_from_list)
+                .unwrap_or_default(),
+            build_commands: item
+                .get("build_commands")
+                .and_then(|v| v.as_l().ok())
+                .map(Self::commands_from_list)
+                .unwrap_or_default(),
+            output_folder: item.get("output_folder")?.as_s().ok()?.to_string(),
+            artifact_glob: item.get("artifact_glob")?.as_s().ok()?.to_string(),
+            env_vars: item
+                .get("env_vars")
+                .and_then(|v| v.as_m().ok())
+                .map(|vars| {
+                    vars.iter()
+                        .filter_map(|(name, value)| Some((name.clone(), value.as_s().ok()?.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    fn commands_from_list(commands: &[AttributeValue]) -> Vec<String> {
+        commands
+            .iter()
+            .filter_map(|command| command.as_s().ok().map(String::from))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl HandlerCreate for ProjectsHandler {
+    type Error = ProjectError;
+    type Payload = ProjectCreatePayload;
+    type Output = Project;
+
+    #[instrument(skip(self, payload))]
+    async fn create(&self, payload: ProjectCreatePayload) -> Result<Project, Report<ProjectError>> {
+        let project = Project::new(payload);
+
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(project.as_hashmap()))
+            .send()
+            .await
+            .change_context(ProjectError)?;
+
+        Ok(project)
+    }
+}
+
+#[async_trait]
+impl HandlerGet for ProjectsHandler {
+    type Error = ProjectError;
+    type Output = Project;
+
+    #[instrument(skip(self))]
+    async fn get(&self, uuid: String) -> Result<Option<Project>, Report<ProjectError>> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key("uuid", AttributeValue::S(uuid))
+            .send()
+            .await
+            .change_context(ProjectError)?;
+
+        Ok(result.item().and_then(Self::from_item))
+    }
+}
+
+#[async_trait]
+impl HandlerUpdate for ProjectsHandler {
+    type Error = ProjectError;
+    type Payload = ProjectUpdatePayload;
+    type Output = Project;
+
+    #[instrument(skip(self, payload))]
+    async fn update(&self, payload: ProjectUpdatePayload) -> Result<Project, Report<ProjectError>> {
+        self.get(payload.uuid.clone())
+            .await?
+            .ok_or_else(|| Report::new(ProjectError))?;
+
+        let project = Project {
+            uuid: payload.uuid,
+            name: payload.name,
+            codebuild_project_name: payload.codebuild_project_name,
+            repo_url: payload.repo_url,
+            install_commands: payload.install_commands,
+            build_commands: payload.build_commands,
+            output_folder: payload.output_folder,
+            artifact_glob: payload.artifact_glob,
+            env_vars: payload.env_vars,
+        };
+
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(project.as_hashmap()))
+            .send()
+            .await
+            .change_context(ProjectError)?;
+
+        Ok(project)
+    }
+}
+
+#[async_trait]
+impl HandlerDelete for ProjectsHandler {
+    type Error = ProjectError;
+
+    #[instrument(skip(self))]
+    async fn delete(&self, uuid: String) -> Result<(), Report<ProjectError>> {
+        self.client
+            .delete_item()
+            .table_name(&self.table)
+            .key("uuid", AttributeValue::S(uuid))
+            .send()
+            .await
+            .change_context(ProjectError)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HandlerList for ProjectsHandler {
+    type Error = ProjectError;
+    type Output = Project;
+
+    #[instrument(skip(self))]
+    async fn list(
+        &self,
+        cursor: Option<String>,
+        limit: Option<i32>,
+    ) -> Result<ListPage<Project>, Report<ProjectError>> {
+        let result = self
+            .client
+            .scan()
+            .table_name(&self.table)
+            .set_exclusive_start_key(cursor.map(decode_cursor))
+            .set_limit(limit)
+            .send()
+            .await
+            .change_context(ProjectError)?;
+
+        Ok(ListPage {
+            items: result
+                .items()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(Self::from_item)
+                .collect(),
+            next_cursor: encode_cursor(result.last_evaluated_key()),
+        })
+    }
+}
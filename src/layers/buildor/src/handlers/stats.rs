@@ -0,0 +1,110 @@
+use aws_sdk_dynamodb::Client;
+use error_stack::{Report, ResultExt};
+
+use crate::handlers::project_deployments::ProjectDeploymentsHandler;
+use crate::models::common::AsDynamoDBAttributeValue;
+use crate::models::handlers::HandlerList;
+use crate::models::project_deployment::BuildStatus;
+use crate::models::stats::{ProjectDeploymentStats, Stats, StatsError};
+
+pub struct StatsHandler {
+    client: Client,
+    table: String,
+    deployments: ProjectDeploymentsHandler,
+}
+impl StatsHandler {
+    pub fn new(client: Client, table: String, deployments_table: String) -> Self {
+        Self {
+            deployments: ProjectDeploymentsHandler::new(client.clone(), deployments_table),
+            client,
+            table,
+        }
+    }
+
+    /// Persists a computed snapshot to the stats table, overwriting the
+    /// previous one under [`crate::models::stats::STATS_SNAPSHOT_UUID`].
+    pub async fn cache(&self, stats: &Stats) -> Result<(), Report<StatsError>> {
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(stats.as_hashmap()))
+            .send()
+            .await
+            .change_context(StatsError)?;
+
+        Ok(())
+    }
+
+    /// Pages through every deployment record, rolling them up into a single
+    /// [`Stats`] snapshot: status counts, per-project totals, and the average
+    /// build duration derived from stored phase timestamps.
+    pub async fn compute(&self) -> Result<Stats, Report<StatsError>> {
+        let mut stats = Stats::empty();
+        let mut duration_sum_seconds: i64 = 0;
+        let mut duration_samples: u64 = 0;
+        let mut cursor = None;
+
+        loop {
+            let page = self
+                .deployments
+                .list(cursor, None)
+                .await
+                .change_context(StatsError)?;
+
+            for deployment in page.items.iter() {
+                stats.total_deployments += 1;
+
+                match &deployment.status {
+                    BuildStatus::InProgress => stats.in_progress += 1,
+                    BuildStatus::Succeeded => stats.succeeded += 1,
+                    BuildStatus::Failed => stats.failed += 1,
+                    BuildStatus::Stopped => stats.stopped += 1,
+                    BuildStatus::Unknown => {}
+                }
+
+                let project_stats = stats
+                    .per_project
+                    .entry(deployment.project.uuid.clone())
+                    .or_insert_with(|| ProjectDeploymentStats {
+                        project_name: deployment.project.name.clone(),
+                        total: 0,
+                        succeeded: 0,
+                        failed: 0,
+                    });
+                project_stats.total += 1;
+                match &deployment.status {
+                    BuildStatus::Succeeded => project_stats.succeeded += 1,
+                    BuildStatus::Failed => project_stats.failed += 1,
+                    _ => {}
+                }
+
+                let mut build_duration: Option<i64> = None;
+                for phase in deployment.phases.iter() {
+                    if let Some(duration) = phase.duration_in_seconds {
+                        *build_duration.get_or_insert(0) += duration;
+                    }
+                }
+                if let Some(duration) = build_duration {
+                    duration_sum_seconds += duration;
+                    duration_samples += 1;
+                }
+            }
+
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        if stats.total_deployments > 0 {
+            stats.success_ratio = stats.succeeded as f64 / stats.total_deployments as f64;
+            stats.failure_ratio = stats.failed as f64 / stats.total_deployments as f64;
+        }
+        if duration_samples > 0 {
+            stats.average_build_duration_seconds =
+                duration_sum_seconds as f64 / duration_samples as f64;
+        }
+
+        Ok(stats)
+    }
+}
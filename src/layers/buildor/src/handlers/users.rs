@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::model::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use error_stack::{Report, ResultExt};
+use std::collections::HashMap;
+use tracing::instrument;
+
+use crate::models::common::AsDynamoDBAttributeValue;
+use crate::models::handlers::{
+    decode_cursor, encode_cursor, HandlerCreate, HandlerDelete, HandlerGet, HandlerList,
+    HandlerUpdate, ListPage,
+};
+use crate::models::user::{User, UserCreatePayload, UserError, UserUpdatePayload};
+
+pub struct UsersHandler {
+    client: Client,
+    table: String,
+}
+impl UsersHandler {
+    pub fn new(client: Client, table: String) -> Self {
+        Self { client, table }
+    }
+
+    fn from_item(item: &HashMap<String, AttributeValue>) -> Option<User> {
+        Some(User {
+            uuid: item.get("uuid")?.as_s().ok()?.to_string(),
+            username: item.get("username")?.as_s().ok()?.to_string(),
+            email: item.get("email")?.as_s().ok()?.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl HandlerCreate for UsersHandler {
+    type Error = UserError;
+    type Payload = UserCreatePayload;
+    type Output = User;
+
+    #[instrument(skip(self, payload))]
+    async fn create(&self, payload: UserCreatePayload) -> Result<User, Report<UserError>> {
+        let user = User::new(payload);
+
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(user.as_hashmap()))
+            .send()
+            .await
+            .change_context(UserError)?;
+
+        Ok(user)
+    }
+}
+
+#[async_trait]
+impl HandlerGet for UsersHandler {
+    type Error = UserError;
+    type Output = User;
+
+    #[instrument(skip(self))]
+    async fn get(&self, uuid: String) -> Result<Option<User>, Report<UserError>> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key("uuid", AttributeValue::S(uuid))
+            .send()
+            .await
+            .change_context(UserError)?;
+
+        Ok(result.item().and_then(Self::from_item))
+    }
+}
+
+#[async_trait]
+impl HandlerUpdate for UsersHandler {
+    type Error = UserError;
+    type Payload = UserUpdatePayload;
+    type Output = User;
+
+    #[instrument(skip(self, payload))]
+    async fn update(&self, payload: UserUpdatePayload) -> Result<User, Report<UserError>> {
+        self.get(payload.uuid.clone())
+            .await?
+            .ok_or_else(|| Report::new(UserError))?;
+
+        let user = User {
+            uuid: payload.uuid,
+            username: payload.username,
+            email: payload.email,
+        };
+
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(user.as_hashmap()))
+            .send()
+            .await
+            .change_context(UserError)?;
+
+        Ok(user)
+    }
+}
+
+#[async_trait]
+impl HandlerDelete for UsersHandler {
+    type Error = UserError;
+
+    #[instrument(skip(self))]
+    async fn delete(&self, uuid: String) -> Result<(), Report<UserError>> {
+        self.client
+            .delete_item()
+            .table_name(&self.table)
+            .key("uuid", AttributeValue::S(uuid))
+            .send()
+            .await
+            .change_context(UserError)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HandlerList for UsersHandler {
+    type Error = UserError;
+    type Output = User;
+
+    #[instrument(skip(self))]
+    async fn list(
+        &self,
+        cursor: Option<String>,
+        limit: Option<i32>,
+    ) -> Result<ListPage<User>, Report<UserError>> {
+        let result = self
+            .client
+            .scan()
+            .table_name(&self.table)
+            .set_exclusive_start_key(cursor.map(decode_cursor))
+            .set_limit(limit)
+            .send()
+            .await
+            .change_context(UserError)?;
+
+        Ok(ListPage {
+            items: result
+                .items()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(Self::from_item)
+                .collect(),
+            next_cursor: encode_cursor(result.last_evaluated_key()),
+        })
+    }
+}
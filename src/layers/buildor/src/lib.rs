@@ -0,0 +1,5 @@
+pub mod handlers;
+pub mod migrator;
+pub mod models;
+pub mod telemetry;
+pub mod utils;
@@ -0,0 +1,311 @@
+use aws_sdk_dynamodb::model::{
+    AttributeDefinition, AttributeValue, BillingMode, KeySchemaElement, KeyType,
+    ScalarAttributeType,
+};
+use aws_sdk_dynamodb::Client;
+use error_stack::{Context, Report, ResultExt};
+use std::collections::HashSet;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+/* Migration Error */
+#[derive(Debug)]
+pub struct MigrationError;
+impl fmt::Display for MigrationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(format!("Migration error").as_str())
+    }
+}
+impl Context for MigrationError {}
+
+/// Metadata table tracking which migration ids have already run.
+const MIGRATIONS_TABLE: &str = "_migrations";
+
+/// The table names every handler in the series reads from its own
+/// `TABLE_NAME*` env vars, collected here so the migrator creates the exact
+/// tables the handlers will actually read from.
+pub struct TableNames {
+    pub users: String,
+    pub projects: String,
+    pub project_deployments: String,
+    pub artifacts: String,
+    pub stats: String,
+}
+
+pub type MigrationUp = Box<
+    dyn Fn(&Client) -> Pin<Box<dyn Future<Output = Result<(), Report<MigrationError>>> + Send + '_>>
+        + Send
+        + Sync,
+>;
+
+/// A single schema change. `id` must be stable and unique - once a migration
+/// has run, its `id` is recorded in [`MIGRATIONS_TABLE`] and it will be
+/// skipped on subsequent runs. `up` itself is not re-entrant: the
+/// `create_*_table` migrations below fail if their table already exists.
+pub struct Migration {
+    pub id: &'static str,
+    pub up: MigrationUp,
+}
+
+/// The ordered list of schema changes this crate depends on. Append new
+/// migrations to the end - never reorder or remove applied ones.
+pub fn migrations(tables: &TableNames) -> Vec<Migration> {
+    let users_table = tables.users.clone();
+    let projects_table = tables.projects.clone();
+    let project_deployments_table = tables.project_deployments.clone();
+    let artifacts_table = tables.artifacts.clone();
+    let stats_table = tables.stats.clone();
+
+    vec![
+        Migration {
+            id: "0001_create_users_table",
+            up: Box::new(move |client| Box::pin(create_users_table(client, users_table.clone()))),
+        },
+        Migration {
+            id: "0002_create_projects_table",
+            up: Box::new(move |client| {
+                Box::pin(create_projects_table(client, projects_table.clone()))
+            }),
+        },
+        Migration {
+            id: "0003_create_project_deployments_table",
+            up: Box::new(move |client| {
+                Box::pin(create_project_deployments_table(
+                    client,
+                    project_deployments_table.clone(),
+                ))
+            }),
+        },
+        Migration {
+            id: "0004_create_artifacts_table",
+            up: Box::new(move |client| {
+                Box::pin(create_artifacts_table(client, artifacts_table.clone()))
+            }),
+        },
+        Migration {
+            id: "0005_create_stats_table",
+            up: Box::new(move |client| Box::pin(create_stats_table(client, stats_table.clone()))),
+        },
+    ]
+}
+
+/// Applies every migration that hasn't run yet, in order, and returns the
+/// ids that were newly applied. Safe to call on every cold start.
+pub async fn run(
+    client: &Client,
+    tables: &TableNames,
+) -> Result<Vec<&'static str>, Report<MigrationError>> {
+    ensure_migrations_table(client).await?;
+    let applied = applied_migration_ids(client).await?;
+
+    let mut newly_applied = Vec::new();
+    for migration in migrations(tables) {
+        if applied.contains(migration.id) {
+            continue;
+        }
+
+        println!("Applying migration: {}", migration.id);
+        (migration.up)(client).await?;
+        record_migration(client, migration.id).await?;
+        newly_applied.push(migration.id);
+    }
+
+    Ok(newly_applied)
+}
+
+async fn ensure_migrations_table(client: &Client) -> Result<(), Report<MigrationError>> {
+    let tables = client
+        .list_tables()
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    if tables
+        .table_names()
+        .unwrap_or_default()
+        .iter()
+        .any(|name| name == MIGRATIONS_TABLE)
+    {
+        return Ok(());
+    }
+
+    client
+        .create_table()
+        .table_name(MIGRATIONS_TABLE)
+        .key_schema(
+            KeySchemaElement::builder()
+                .attribute_name("id")
+                .key_type(KeyType::Hash)
+                .build(),
+        )
+        .attribute_definitions(
+            AttributeDefinition::builder()
+                .attribute_name("id")
+                .attribute_type(ScalarAttributeType::S)
+                .build(),
+        )
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    Ok(())
+}
+
+async fn applied_migration_ids(client: &Client) -> Result<HashSet<String>, Report<MigrationError>> {
+    let result = client
+        .scan()
+        .table_name(MIGRATIONS_TABLE)
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    Ok(result
+        .items()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|item| item.get("id")?.as_s().ok().map(String::from))
+        .collect())
+}
+
+async fn record_migration(client: &Client, id: &str) -> Result<(), Report<MigrationError>> {
+    client
+        .put_item()
+        .table_name(MIGRATIONS_TABLE)
+        .item("id", AttributeValue::S(id.to_string()))
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    Ok(())
+}
+
+async fn create_users_table(client: &Client, table: String) -> Result<(), Report<MigrationError>> {
+    client
+        .create_table()
+        .table_name(table)
+        .key_schema(
+            KeySchemaElement::builder()
+                .attribute_name("uuid")
+                .key_type(KeyType::Hash)
+                .build(),
+        )
+        .attribute_definitions(
+            AttributeDefinition::builder()
+                .attribute_name("uuid")
+                .attribute_type(ScalarAttributeType::S)
+                .build(),
+        )
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    Ok(())
+}
+
+async fn create_projects_table(
+    client: &Client,
+    table: String,
+) -> Result<(), Report<MigrationError>> {
+    client
+        .create_table()
+        .table_name(table)
+        .key_schema(
+            KeySchemaElement::builder()
+                .attribute_name("uuid")
+                .key_type(KeyType::Hash)
+                .build(),
+        )
+        .attribute_definitions(
+            AttributeDefinition::builder()
+                .attribute_name("uuid")
+                .attribute_type(ScalarAttributeType::S)
+                .build(),
+        )
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    Ok(())
+}
+
+async fn create_project_deployments_table(
+    client: &Client,
+    table: String,
+) -> Result<(), Report<MigrationError>> {
+    client
+        .create_table()
+        .table_name(table)
+        .key_schema(
+            KeySchemaElement::builder()
+                .attribute_name("uuid")
+                .key_type(KeyType::Hash)
+                .build(),
+        )
+        .attribute_definitions(
+            AttributeDefinition::builder()
+                .attribute_name("uuid")
+                .attribute_type(ScalarAttributeType::S)
+                .build(),
+        )
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    Ok(())
+}
+
+async fn create_artifacts_table(
+    client: &Client,
+    table: String,
+) -> Result<(), Report<MigrationError>> {
+    client
+        .create_table()
+        .table_name(table)
+        .key_schema(
+            KeySchemaElement::builder()
+                .attribute_name("uuid")
+                .key_type(KeyType::Hash)
+                .build(),
+        )
+        .attribute_definitions(
+            AttributeDefinition::builder()
+                .attribute_name("uuid")
+                .attribute_type(ScalarAttributeType::S)
+                .build(),
+        )
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    Ok(())
+}
+
+async fn create_stats_table(client: &Client, table: String) -> Result<(), Report<MigrationError>> {
+    client
+        .create_table()
+        .table_name(table)
+        .key_schema(
+            KeySchemaElement::builder()
+                .attribute_name("uuid")
+                .key_type(KeyType::Hash)
+                .build(),
+        )
+        .attribute_definitions(
+            AttributeDefinition::builder()
+                .attribute_name("uuid")
+                .attribute_type(ScalarAttributeType::S)
+                .build(),
+        )
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .change_context(MigrationError)?;
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use aws_sdk_dynamodb::model::AttributeValue;
+use error_stack::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+use super::common::AsDynamoDBAttributeValue;
+use super::request::RequestError;
+
+/* Artifact */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Artifact {
+    pub uuid: String,
+    pub deployment_uuid: String,
+    pub bucket: String,
+    pub key: String,
+    pub size: Option<i64>,
+    pub content_type: Option<String>,
+}
+impl Artifact {
+    pub fn new(payload: ArtifactCreatePayload) -> Self {
+        Self {
+            uuid: Uuid::new_v4().to_string(),
+            deployment_uuid: payload.deployment_uuid,
+            bucket: payload.bucket,
+            key: payload.key,
+            size: payload.size,
+            content_type: payload.content_type,
+        }
+    }
+}
+impl AsDynamoDBAttributeValue for Artifact {
+    fn as_hashmap(&self) -> HashMap<String, AttributeValue> {
+        HashMap::from([
+            ("uuid".to_string(), AttributeValue::S(self.uuid.clone())),
+            (
+                "deployment_uuid".to_string(),
+                AttributeValue::S(self.deployment_uuid.clone()),
+            ),
+            ("bucket".to_string(), AttributeValue::S(self.bucket.clone())),
+            ("key".to_string(), AttributeValue::S(self.key.clone())),
+            (
+                "size".to_string(),
+                match self.size {
+                    Some(size) => AttributeValue::N(size.to_string()),
+                    None => AttributeValue::Null(true),
+                },
+            ),
+            (
+                "content_type".to_string(),
+                match &self.content_type {
+                    Some(content_type) => AttributeValue::S(content_type.clone()),
+                    None => AttributeValue::Null(true),
+                },
+            ),
+        ])
+    }
+
+    fn as_attr(&self) -> AttributeValue {
+        AttributeValue::M(self.as_hashmap())
+    }
+}
+
+/* Artifact Create Payload */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ArtifactCreatePayload {
+    pub deployment_uuid: String,
+    pub bucket: String,
+    pub key: String,
+    pub size: Option<i64>,
+    pub content_type: Option<String>,
+}
+
+/* Artifact Error */
+#[derive(Debug)]
+pub struct ArtifactError;
+impl ArtifactError {
+    pub fn creation_failed() -> RequestError {
+        RequestError {
+            code: "ART00".to_string(),
+            message: "Artifact Creation Error".to_string(),
+            details: "Failed to create artifact record".to_string(),
+        }
+    }
+
+    pub fn not_found() -> RequestError {
+        RequestError {
+            code: "ART01".to_string(),
+            message: "Artifact Not Found Error".to_string(),
+            details: "Requested artifact does not exist".to_string(),
+        }
+    }
+
+    pub fn presign_failed() -> RequestError {
+        RequestError {
+            code: "ART02".to_string(),
+            message: "Artifact Presign Error".to_string(),
+            details: "Failed to generate a download URL for the artifact".to_string(),
+        }
+    }
+}
+impl fmt::Display for ArtifactError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(format!("Artifact error").as_str())
+    }
+}
+impl Context for ArtifactError {}
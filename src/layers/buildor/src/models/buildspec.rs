@@ -0,0 +1,51 @@
+use serde_json::{json, Value};
+
+use super::project::Project;
+
+/// Renders a project's stored build configuration into a CodeBuild
+/// buildspec.
+///
+/// Builds the spec as a [`Value`] tree rather than a formatted string, so
+/// commands are JSON-escaped by `serde_json` instead of being
+/// string-concatenated by hand.
+pub struct BuildSpecBuilder;
+impl BuildSpecBuilder {
+    pub fn build(project: &Project) -> Value {
+        let mut pre_build_commands = Vec::from(["cd $PROJECT_NAME".to_string()]);
+        pre_build_commands.extend(project.install_commands.clone());
+
+        let mut build_commands = project.build_commands.clone();
+        build_commands.push(format!("mv {} ../dist", project.output_folder));
+        build_commands.push("cd ..".to_string());
+
+        json!({
+            "version": "0.2",
+            "env": {
+                "variables": project.env_vars,
+            },
+            "phases": {
+                "install": {
+                    "commands": [
+                        "echo Download project",
+                        "node -v",
+                        "git clone $REPO_URL $PROJECT_NAME",
+                    ],
+                },
+                "pre_build": {
+                    "commands": pre_build_commands,
+                },
+                "build": {
+                    "commands": build_commands,
+                },
+                "post_build": {
+                    "commands": ["echo Build has completed and artifacts were moved"],
+                },
+            },
+            "artifacts": {
+                "discard-paths": "no",
+                "files": [project.artifact_glob],
+                "name": format!("{}-dist-{}.zip", project.name, "timestamp"),
+            },
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::model::AttributeValue;
+use error_stack::{Context, Report};
+use std::collections::HashMap;
+
+#[async_trait]
+pub trait HandlerCreate {
+    type Error: Context;
+    type Payload;
+    type Output;
+
+    async fn create(&self, payload: Self::Payload) -> Result<Self::Output, Report<Self::Error>>;
+}
+
+#[async_trait]
+pub trait HandlerGet {
+    type Error: Context;
+    type Output;
+
+    async fn get(&self, uuid: String) -> Result<Option<Self::Output>, Report<Self::Error>>;
+}
+
+#[async_trait]
+pub trait HandlerUpdate {
+    type Error: Context;
+    type Payload;
+    type Output;
+
+    async fn update(&self, payload: Self::Payload) -> Result<Self::Output, Report<Self::Error>>;
+}
+
+#[async_trait]
+pub trait HandlerDelete {
+    type Error: Context;
+
+    async fn delete(&self, uuid: String) -> Result<(), Report<Self::Error>>;
+}
+
+/// One page of a [`HandlerList::list`] scan.
+pub struct ListPage<T> {
+    pub items: Vec<T>,
+    /// Opaque cursor to pass back as `HandlerList::list`'s `cursor` argument
+    /// to fetch the next page; `None` once the scan is exhausted.
+    pub next_cursor: Option<String>,
+}
+
+#[async_trait]
+pub trait HandlerList {
+    type Error: Context;
+    type Output;
+
+    async fn list(
+        &self,
+        cursor: Option<String>,
+        limit: Option<i32>,
+    ) -> Result<ListPage<Self::Output>, Report<Self::Error>>;
+}
+
+/// Every table here keys on a single `uuid` partition key, so a pagination
+/// cursor is just that attribute's value off DynamoDB's `LastEvaluatedKey` -
+/// callers should still treat it as opaque.
+pub fn encode_cursor(last_evaluated_key: Option<&HashMap<String, AttributeValue>>) -> Option<String> {
+    last_evaluated_key?.get("uuid")?.as_s().ok().cloned()
+}
+
+/// Rebuilds the `ExclusiveStartKey` a cursor from [`encode_cursor`] came from.
+pub fn decode_cursor(cursor: String) -> HashMap<String, AttributeValue> {
+    HashMap::from([("uuid".to_string(), AttributeValue::S(cursor))])
+}
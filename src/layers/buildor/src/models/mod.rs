@@ -0,0 +1,10 @@
+pub mod artifact;
+pub mod buildspec;
+pub mod common;
+pub mod handlers;
+pub mod project;
+pub mod project_deployment;
+pub mod request;
+pub mod response;
+pub mod stats;
+pub mod user;
@@ -0,0 +1,172 @@
+use aws_sdk_dynamodb::model::AttributeValue;
+use error_stack::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+use super::common::AsDynamoDBAttributeValue;
+use super::request::RequestError;
+
+/* Project */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Project {
+    pub uuid: String,
+    pub name: String,
+    pub codebuild_project_name: String,
+    /// Git URL CodeBuild clones at the start of the `install` phase.
+    pub repo_url: String,
+    /// Commands run in the buildspec's `pre_build` phase, e.g. installing
+    /// dependencies.
+    pub install_commands: Vec<String>,
+    /// Commands run in the buildspec's `build` phase.
+    pub build_commands: Vec<String>,
+    /// Path, relative to the cloned repo, holding the build output that
+    /// gets moved into the artifacts directory.
+    pub output_folder: String,
+    /// Glob (relative to the artifacts directory) CodeBuild packages as the
+    /// build's output artifact.
+    pub artifact_glob: String,
+    /// Extra environment variables exposed to the build under
+    /// `env.variables` in the rendered buildspec.
+    pub env_vars: HashMap<String, String>,
+}
+impl Project {
+    pub fn new(payload: ProjectCreatePayload) -> Self {
+        Self {
+            uuid: Uuid::new_v4().to_string(),
+            name: payload.name,
+            codebuild_project_name: payload.codebuild_project_name,
+            repo_url: payload.repo_url,
+            install_commands: payload.install_commands,
+            build_commands: payload.build_commands,
+            output_folder: payload.output_folder,
+            artifact_glob: payload.artifact_glob,
+            env_vars: payload.env_vars,
+        }
+    }
+}
+impl AsDynamoDBAttributeValue for Project {
+    fn as_hashmap(&self) -> HashMap<String, AttributeValue> {
+        HashMap::from([
+            ("uuid".to_string(), AttributeValue::S(self.uuid.clone())),
+            ("name".to_string(), AttributeValue::S(self.name.clone())),
+            (
+                "codebuild_project_name".to_string(),
+                AttributeValue::S(self.codebuild_project_name.clone()),
+            ),
+            ("repo_url".to_string(), AttributeValue::S(self.repo_url.clone())),
+            (
+                "install_commands".to_string(),
+                AttributeValue::L(
+                    self.install_commands
+                        .iter()
+                        .map(|command| AttributeValue::S(command.clone()))
+                        .collect(),
+                ),
+            ),
+            (
+                "build_commands".to_string(),
+                AttributeValue::L(
+                    self.build_commands
+                        .iter()
+                        .map(|command| AttributeValue::S(command.clone()))
+                        .collect(),
+                ),
+            ),
+            (
+                "output_folder".to_string(),
+                AttributeValue::S(self.output_folder.clone()),
+            ),
+            (
+                "artifact_glob".to_string(),
+                AttributeValue::S(self.artifact_glob.clone()),
+            ),
+            (
+                "env_vars".to_string(),
+                AttributeValue::M(
+                    self.env_vars
+                        .iter()
+                        .map(|(name, value)| (name.clone(), AttributeValue::S(value.clone())))
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    fn as_attr(&self) -> AttributeValue {
+        AttributeValue::M(self.as_hashmap())
+    }
+}
+
+/* Project Create Payload */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectCreatePayload {
+    pub name: String,
+    pub codebuild_project_name: String,
+    pub repo_url: String,
+    pub install_commands: Vec<String>,
+    pub build_commands: Vec<String>,
+    pub output_folder: String,
+    pub artifact_glob: String,
+    #[serde(default)]
+    pub env_vars: HashMap<String, String>,
+}
+
+/* Project Update Payload */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectUpdatePayload {
+    pub uuid: String,
+    pub name: String,
+    pub codebuild_project_name: String,
+    pub repo_url: String,
+    pub install_commands: Vec<String>,
+    pub build_commands: Vec<String>,
+    pub output_folder: String,
+    pub artifact_glob: String,
+    #[serde(default)]
+    pub env_vars: HashMap<String, String>,
+}
+
+/* Project Error */
+#[derive(Debug)]
+pub struct ProjectError;
+impl ProjectError {
+    pub fn creation_failed() -> RequestError {
+        RequestError {
+            code: "PRJ00".to_string(),
+            message: "Project Creation Error".to_string(),
+            details: "Failed to create project".to_string(),
+        }
+    }
+
+    pub fn not_found() -> RequestError {
+        RequestError {
+            code: "PRJ01".to_string(),
+            message: "Project Not Found Error".to_string(),
+            details: "Requested project does not exist".to_string(),
+        }
+    }
+
+    pub fn update_failed() -> RequestError {
+        RequestError {
+            code: "PRJ02".to_string(),
+            message: "Project Update Error".to_string(),
+            details: "Failed to update project".to_string(),
+        }
+    }
+
+    pub fn deletion_failed() -> RequestError {
+        RequestError {
+            code: "PRJ03".to_string(),
+            message: "Project Deletion Error".to_string(),
+            details: "Failed to delete project".to_string(),
+        }
+    }
+}
+impl fmt::Display for ProjectError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(format!("Project error").as_str())
+    }
+}
+impl Context for ProjectError {}
@@ -0,0 +1,240 @@
+use aws_sdk_dynamodb::model::AttributeValue;
+use error_stack::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+use super::common::AsDynamoDBAttributeValue;
+use super::project::Project;
+use super::request::RequestError;
+
+/* Build Info */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BuildInfo {
+    pub id: String,
+    pub arn: Option<String>,
+    pub build_number: Option<i64>,
+    pub start_time: Option<String>,
+}
+
+/* Build Status */
+///
+/// Mirrors CodeBuild's `StatusType` so the deployment record doesn't need to
+/// carry the AWS SDK type directly.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum BuildStatus {
+    InProgress,
+    Succeeded,
+    Failed,
+    Stopped,
+    Unknown,
+}
+impl From<&str> for BuildStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "IN_PROGRESS" => Self::InProgress,
+            "SUCCEEDED" => Self::Succeeded,
+            "FAILED" => Self::Failed,
+            "STOPPED" => Self::Stopped,
+            _ => Self::Unknown,
+        }
+    }
+}
+impl fmt::Display for BuildStatus {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            Self::InProgress => "IN_PROGRESS",
+            Self::Succeeded => "SUCCEEDED",
+            Self::Failed => "FAILED",
+            Self::Stopped => "STOPPED",
+            Self::Unknown => "UNKNOWN",
+        };
+        fmt.write_str(value)
+    }
+}
+
+/* Build Phase */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BuildPhase {
+    pub phase_type: String,
+    pub phase_status: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub duration_in_seconds: Option<i64>,
+}
+
+/* Project Deployment */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectDeployment {
+    pub uuid: String,
+    pub project: Project,
+    pub build: BuildInfo,
+    pub status: BuildStatus,
+    pub phases: Vec<BuildPhase>,
+}
+impl ProjectDeployment {
+    pub fn new(payload: ProjectDeploymentCreatePayload) -> Self {
+        Self {
+            uuid: Uuid::new_v4().to_string(),
+            project: payload.project,
+            build: payload.build,
+            status: BuildStatus::InProgress,
+            phases: Vec::new(),
+        }
+    }
+}
+impl AsDynamoDBAttributeValue for ProjectDeployment {
+    fn as_hashmap(&self) -> HashMap<String, AttributeValue> {
+        HashMap::from([
+            ("uuid".to_string(), AttributeValue::S(self.uuid.clone())),
+            ("project".to_string(), self.project.as_attr()),
+            (
+                "build".to_string(),
+                AttributeValue::M(HashMap::from([
+                    ("id".to_string(), AttributeValue::S(self.build.id.clone())),
+                    (
+                        "arn".to_string(),
+                        match &self.build.arn {
+                            Some(arn) => AttributeValue::S(arn.clone()),
+                            None => AttributeValue::Null(true),
+                        },
+                    ),
+                    (
+                        "build_number".to_string(),
+                        match self.build.build_number {
+                            Some(n) => AttributeValue::N(n.to_string()),
+                            None => AttributeValue::Null(true),
+                        },
+                    ),
+                    (
+                        "start_time".to_string(),
+                        match &self.build.start_time {
+                            Some(t) => AttributeValue::S(t.clone()),
+                            None => AttributeValue::Null(true),
+                        },
+                    ),
+                ])),
+            ),
+            (
+                "status".to_string(),
+                AttributeValue::S(self.status.to_string()),
+            ),
+            (
+                "phases".to_string(),
+                AttributeValue::L(
+                    self.phases
+                        .iter()
+                        .map(|phase| {
+                            AttributeValue::M(HashMap::from([
+                                (
+                                    "phase_type".to_string(),
+                                    AttributeValue::S(phase.phase_type.clone()),
+                                ),
+                                (
+                                    "phase_status".to_string(),
+                                    match &phase.phase_status {
+                                        Some(status) => AttributeValue::S(status.clone()),
+                                        None => AttributeValue::Null(true),
+                                    },
+                                ),
+                                (
+                                    "start_time".to_string(),
+                                    match &phase.start_time {
+                                        Some(t) => AttributeValue::S(t.clone()),
+                                        None => AttributeValue::Null(true),
+                                    },
+                                ),
+                                (
+                                    "end_time".to_string(),
+                                    match &phase.end_time {
+                                        Some(t) => AttributeValue::S(t.clone()),
+                                        None => AttributeValue::Null(true),
+                                    },
+                                ),
+                                (
+                                    "duration_in_seconds".to_string(),
+                                    match phase.duration_in_seconds {
+                                        Some(d) => AttributeValue::N(d.to_string()),
+                                        None => AttributeValue::Null(true),
+                                    },
+                                ),
+                            ]))
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    fn as_attr(&self) -> AttributeValue {
+        AttributeValue::M(self.as_hashmap())
+    }
+}
+
+/* Project Deployment Create Payload */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectDeploymentCreatePayload {
+    pub project: Project,
+    pub build: BuildInfo,
+}
+
+/* Project Deployment Create Payload Request */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectDeploymentCreatePayloadRequest {
+    pub project_uuid: String,
+}
+
+/* Project Deployment Update Payload */
+///
+/// Built from a `batch_get_builds` response by [`BuildStatusHandler`](crate::handlers::build_status::BuildStatusHandler);
+/// applies the latest CodeBuild status and phase timestamps to a stored deployment.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectDeploymentUpdatePayload {
+    pub uuid: String,
+    pub status: BuildStatus,
+    pub phases: Vec<BuildPhase>,
+}
+
+/* Project Deployment Error */
+#[derive(Debug)]
+pub struct ProjectDeploymentError;
+impl ProjectDeploymentError {
+    pub fn creation_failed() -> RequestError {
+        RequestError {
+            code: "PDP00".to_string(),
+            message: "Project Deployment Creation Error".to_string(),
+            details: "Failed to create project deployment".to_string(),
+        }
+    }
+
+    pub fn not_found() -> RequestError {
+        RequestError {
+            code: "PDP01".to_string(),
+            message: "Project Deployment Not Found Error".to_string(),
+            details: "Requested project deployment does not exist".to_string(),
+        }
+    }
+
+    pub fn update_failed() -> RequestError {
+        RequestError {
+            code: "PDP02".to_string(),
+            message: "Project Deployment Update Error".to_string(),
+            details: "Failed to update project deployment".to_string(),
+        }
+    }
+
+    pub fn deletion_failed() -> RequestError {
+        RequestError {
+            code: "PDP03".to_string(),
+            message: "Project Deployment Deletion Error".to_string(),
+            details: "Failed to delete project deployment".to_string(),
+        }
+    }
+}
+impl fmt::Display for ProjectDeploymentError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(format!("Project deployment error").as_str())
+    }
+}
+impl Context for ProjectDeploymentError {}
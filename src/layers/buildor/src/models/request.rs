@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/* Request Error */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RequestError {
+    pub code: String,
+    pub message: String,
+    pub details: String,
+}
+impl RequestError {
+    pub fn internal() -> Self {
+        Self {
+            code: "REQ00".to_string(),
+            message: "Internal Server Error".to_string(),
+            details: "An unexpected error occurred while processing the request".to_string(),
+        }
+    }
+}
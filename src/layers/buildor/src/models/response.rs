@@ -0,0 +1,15 @@
+use serde_json::{json, Value};
+
+/* Response */
+pub struct Response;
+impl Response {
+    pub fn new(body: Value, status_code: u16) -> Value {
+        json!({
+            "statusCode": status_code,
+            "headers": {
+                "Content-Type": "application/json"
+            },
+            "body": body.to_string(),
+        })
+    }
+}
@@ -0,0 +1,146 @@
+use aws_sdk_dynamodb::model::AttributeValue;
+use error_stack::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use super::common::AsDynamoDBAttributeValue;
+use super::request::RequestError;
+
+/// Key the latest snapshot is cached under in the stats table - the rollup
+/// isn't scoped to a single resource, so there's only ever one row.
+pub const STATS_SNAPSHOT_UUID: &str = "latest";
+
+/* Project Deployment Stats */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectDeploymentStats {
+    pub project_name: String,
+    pub total: u64,
+    pub succeeded: u64,
+    pub failed: u64,
+}
+
+/* Stats */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Stats {
+    pub uuid: String,
+    pub total_deployments: u64,
+    pub succeeded: u64,
+    pub failed: u64,
+    pub in_progress: u64,
+    pub stopped: u64,
+    pub success_ratio: f64,
+    pub failure_ratio: f64,
+    pub average_build_duration_seconds: f64,
+    /// Keyed by project uuid.
+    pub per_project: HashMap<String, ProjectDeploymentStats>,
+}
+impl Stats {
+    pub fn empty() -> Self {
+        Self {
+            uuid: STATS_SNAPSHOT_UUID.to_string(),
+            total_deployments: 0,
+            succeeded: 0,
+            failed: 0,
+            in_progress: 0,
+            stopped: 0,
+            success_ratio: 0.0,
+            failure_ratio: 0.0,
+            average_build_duration_seconds: 0.0,
+            per_project: HashMap::new(),
+        }
+    }
+}
+impl AsDynamoDBAttributeValue for Stats {
+    fn as_hashmap(&self) -> HashMap<String, AttributeValue> {
+        HashMap::from([
+            ("uuid".to_string(), AttributeValue::S(self.uuid.clone())),
+            (
+                "total_deployments".to_string(),
+                AttributeValue::N(self.total_deployments.to_string()),
+            ),
+            (
+                "succeeded".to_string(),
+                AttributeValue::N(self.succeeded.to_string()),
+            ),
+            (
+                "failed".to_string(),
+                AttributeValue::N(self.failed.to_string()),
+            ),
+            (
+                "in_progress".to_string(),
+                AttributeValue::N(self.in_progress.to_string()),
+            ),
+            (
+                "stopped".to_string(),
+                AttributeValue::N(self.stopped.to_string()),
+            ),
+            (
+                "success_ratio".to_string(),
+                AttributeValue::N(self.success_ratio.to_string()),
+            ),
+            (
+                "failure_ratio".to_string(),
+                AttributeValue::N(self.failure_ratio.to_string()),
+            ),
+            (
+                "average_build_duration_seconds".to_string(),
+                AttributeValue::N(self.average_build_duration_seconds.to_string()),
+            ),
+            (
+                "per_project".to_string(),
+                AttributeValue::M(
+                    self.per_project
+                        .iter()
+                        .map(|(project_uuid, stats)| {
+                            (
+                                project_uuid.clone(),
+                                AttributeValue::M(HashMap::from([
+                                    (
+                                        "project_name".to_string(),
+                                        AttributeValue::S(stats.project_name.clone()),
+                                    ),
+                                    (
+                                        "total".to_string(),
+                                        AttributeValue::N(stats.total.to_string()),
+                                    ),
+                                    (
+                                        "succeeded".to_string(),
+                                        AttributeValue::N(stats.succeeded.to_string()),
+                                    ),
+                                    (
+                                        "failed".to_string(),
+                                        AttributeValue::N(stats.failed.to_string()),
+                                    ),
+                                ])),
+                            )
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    fn as_attr(&self) -> AttributeValue {
+        AttributeValue::M(self.as_hashmap())
+    }
+}
+
+/* Stats Error */
+#[derive(Debug)]
+pub struct StatsError;
+impl StatsError {
+    pub fn computation_failed() -> RequestError {
+        RequestError {
+            code: "STA00".to_string(),
+            message: "Stats Computation Error".to_string(),
+            details: "Failed to compute deployment stats".to_string(),
+        }
+    }
+}
+impl fmt::Display for StatsError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(format!("Stats error").as_str())
+    }
+}
+impl Context for StatsError {}
@@ -0,0 +1,100 @@
+use aws_sdk_dynamodb::model::AttributeValue;
+use error_stack::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+use super::common::AsDynamoDBAttributeValue;
+use super::request::RequestError;
+
+/* User */
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct User {
+    pub uuid: String,
+    pub username: String,
+    pub email: String,
+}
+impl User {
+    pub fn new(payload: UserCreatePayload) -> Self {
+        Self {
+            uuid: Uuid::new_v4().to_string(),
+            username: payload.username,
+            email: payload.email,
+        }
+    }
+}
+impl AsDynamoDBAttributeValue for User {
+    fn as_hashmap(&self) -> HashMap<String, AttributeValue> {
+        HashMap::from([
+            ("uuid".to_string(), AttributeValue::S(self.uuid.clone())),
+            (
+                "username".to_string(),
+                AttributeValue::S(self.username.clone()),
+            ),
+            ("email".to_string(), AttributeValue::S(self.email.clone())),
+        ])
+    }
+
+    fn as_attr(&self) -> AttributeValue {
+        AttributeValue::M(self.as_hashmap())
+    }
+}
+
+/* User Create Payload */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserCreatePayload {
+    pub username: String,
+    pub email: String,
+}
+
+/* User Update Payload */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserUpdatePayload {
+    pub uuid: String,
+    pub username: String,
+    pub email: String,
+}
+
+/* User Error */
+#[derive(Debug)]
+pub struct UserError;
+impl UserError {
+    pub fn creation_failed() -> RequestError {
+        RequestError {
+            code: "USR00".to_string(),
+            message: "User Creation Error".to_string(),
+            details: "Failed to create user".to_string(),
+        }
+    }
+
+    pub fn not_found() -> RequestError {
+        RequestError {
+            code: "USR01".to_string(),
+            message: "User Not Found Error".to_string(),
+            details: "Requested user does not exist".to_string(),
+        }
+    }
+
+    pub fn update_failed() -> RequestError {
+        RequestError {
+            code: "USR02".to_string(),
+            message: "User Update Error".to_string(),
+            details: "Failed to update user".to_string(),
+        }
+    }
+
+    pub fn deletion_failed() -> RequestError {
+        RequestError {
+            code: "USR03".to_string(),
+            message: "User Deletion Error".to_string(),
+            details: "Failed to delete user".to_string(),
+        }
+    }
+}
+impl fmt::Display for UserError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(format!("User error").as_str())
+    }
+}
+impl Context for UserError {}
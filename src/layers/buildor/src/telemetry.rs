@@ -0,0 +1,33 @@
+use lambda_runtime::Context;
+use tracing_subscriber::EnvFilter;
+
+/// Initializes the process-wide JSON tracing subscriber.
+///
+/// Call once from each Lambda entry point's `main`, before the runtime
+/// starts polling for events. Honors `RUST_LOG` via [`EnvFilter`],
+/// defaulting to `info` when unset, so verbosity can be raised per function
+/// without a redeploy.
+pub fn init() {
+    tracing_subscriber::fmt()
+        .json()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with_target(false)
+        .init();
+}
+
+/// Opens the root span for a single invocation, seeded with the Lambda
+/// request id and function name so every event logged while it's entered
+/// can be correlated back to one invocation in CloudWatch.
+pub fn request_span(context: &Context) -> tracing::Span {
+    tracing::info_span!(
+        "lambda_invocation",
+        request_id = %context.request_id,
+        function_name = %function_name_from_arn(&context.invoked_function_arn),
+    )
+}
+
+/// Pulls the function name off the tail of an invoked-function ARN
+/// (`arn:aws:lambda:region:account:function:name`).
+fn function_name_from_arn(arn: &str) -> &str {
+    arn.rsplit(':').next().unwrap_or(arn)
+}
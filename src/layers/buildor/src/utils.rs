@@ -0,0 +1,113 @@
+use aws_sdk_codebuild::output::StartBuildOutput;
+use aws_types::SdkConfig;
+use error_stack::Report;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use tokio::sync::OnceCell;
+
+use crate::models::common::{CommonError, RequiredEnvVarError};
+use crate::models::project_deployment::BuildInfo;
+
+/// Loads an environment variable, falling back to `default` when unset.
+pub fn load_env_var(name: &str, default: Option<&str>) -> Result<String, Report<RequiredEnvVarError>> {
+    match std::env::var(name) {
+        Ok(value) => Ok(value),
+        Err(_) => match default {
+            Some(value) => Ok(value.to_string()),
+            None => Err(Report::new(RequiredEnvVarError::new(name))),
+        },
+    }
+}
+
+/// Builds the AWS SDK config once and hands back a DynamoDB client.
+///
+/// Kept around for handlers that haven't been migrated to [`Clients::dynamodb`] yet.
+pub async fn get_table_client() -> aws_sdk_dynamodb::Client {
+    Clients::dynamodb().await
+}
+
+static AWS_CONFIG: OnceCell<SdkConfig> = OnceCell::const_new();
+static DYNAMODB_CLIENT: OnceCell<aws_sdk_dynamodb::Client> = OnceCell::const_new();
+static CODEBUILD_CLIENT: OnceCell<aws_sdk_codebuild::Client> = OnceCell::const_new();
+static S3_CLIENT: OnceCell<aws_sdk_s3::Client> = OnceCell::const_new();
+
+/// Loads `aws_config` from the environment once per process and reuses it
+/// for every client below, so a warm Lambda invocation never pays the
+/// credential-provider round trip twice.
+async fn aws_config() -> &'static SdkConfig {
+    AWS_CONFIG
+        .get_or_init(|| async { aws_config::load_from_env().await })
+        .await
+}
+
+/// Lazily constructed AWS SDK clients, shared by the handlers.
+///
+/// Each client is built at most once per warm Lambda execution environment
+/// and cloned out on every call - the generated clients are cheap to clone,
+/// wrapping their inner state in an `Arc`.
+pub struct Clients;
+impl Clients {
+    pub async fn dynamodb() -> aws_sdk_dynamodb::Client {
+        DYNAMODB_CLIENT
+            .get_or_init(|| async { aws_sdk_dynamodb::Client::new(aws_config().await) })
+            .await
+            .clone()
+    }
+
+    pub async fn codebuild() -> aws_sdk_codebuild::Client {
+        CODEBUILD_CLIENT
+            .get_or_init(|| async { aws_sdk_codebuild::Client::new(aws_config().await) })
+            .await
+            .clone()
+    }
+
+    pub async fn s3() -> aws_sdk_s3::Client {
+        S3_CLIENT
+            .get_or_init(|| async { aws_sdk_s3::Client::new(aws_config().await) })
+            .await
+            .clone()
+    }
+}
+
+/// Splits a CodeBuild artifact location ARN (`arn:aws:s3:::bucket/key/...`)
+/// into its bucket and key.
+pub fn parse_s3_artifact_location(location: &str) -> Option<(String, String)> {
+    let path = location.strip_prefix("arn:aws:s3:::").unwrap_or(location);
+    let (bucket, key) = path.split_once('/')?;
+
+    Some((bucket.to_string(), key.to_string()))
+}
+
+/// Extracts the fields the deployment record needs to track a build from a
+/// `start_build` response.
+pub fn get_build_info(output: &StartBuildOutput) -> Option<BuildInfo> {
+    let build = output.build()?;
+
+    Some(BuildInfo {
+        id: build.id()?.to_string(),
+        arn: build.arn().map(|arn| arn.to_string()),
+        build_number: build.build_number(),
+        start_time: build.start_time().map(|t| t.to_string()),
+    })
+}
+
+/// Parses a Lambda event's JSON-encoded `body` string into `T`, returning a
+/// ready-to-return error response on failure.
+pub fn parse_request_body_payload<T: DeserializeOwned>(body: &Value) -> Result<T, Value> {
+    let raw: Cow<'_, str> = match body.as_str() {
+        Some(value) => Cow::from(value),
+        None => {
+            return Err(json!(CommonError::schema_compliant(
+                "Body payload is missing".to_string()
+            )))
+        }
+    };
+
+    serde_json::from_str::<T>(&raw).map_err(|err| {
+        json!(CommonError::schema_compliant(format!(
+            "Body payload not compliant: {}",
+            err
+        )))
+    })
+}